@@ -0,0 +1,33 @@
+use ::Future;
+use ::std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Future returned by `Future::catch_unwind()`.
+///
+/// `TSFuture`/`Glue` treat a panic from `poll()` as the future resolving; `CatchUnwind` turns
+/// that into an observable value instead of letting it propagate, since polling the original
+/// future again afterwards would be UB.
+pub struct CatchUnwind<F>(F);
+
+impl<F> CatchUnwind<F> {
+    pub(crate) fn new(f: F) -> Self {
+        CatchUnwind(f)
+    }
+}
+
+impl<F> Future for CatchUnwind<F> where F: Future + ::std::panic::UnwindSafe {
+    type Item = Result<F::Item, Box<::std::any::Any + Send>>;
+    type Error = F::Error;
+
+    fn poll(self) -> ::Poll<Self> {
+        let CatchUnwind(f) = self;
+
+        match catch_unwind(AssertUnwindSafe(|| f.poll())) {
+            Ok(::Poll::Success(item)) => ::Poll::Success(Ok(item)),
+            Ok(::Poll::Failure(e)) => ::Poll::Failure(e),
+            Ok(::Poll::NotReady(f)) => ::Poll::NotReady(CatchUnwind(f)),
+            // A panic from `poll()` is, by this crate's contract, a resolution of the future -
+            // surface it as a value instead of letting it keep unwinding.
+            Err(payload) => ::Poll::Success(Err(payload)),
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use ::Future;
+
+/// Future returned by `Future::map()`.
+pub struct Map<F, G>(F, G);
+
+impl<F, G> Map<F, G> {
+    pub(crate) fn new(f: F, g: G) -> Self {
+        Map(f, g)
+    }
+}
+
+impl<F, G, U> Future for Map<F, G> where F: Future, G: FnOnce(F::Item) -> U {
+    type Item = U;
+    type Error = F::Error;
+
+    fn poll(self) -> ::Poll<Self> {
+        let Map(f, g) = self;
+
+        match f.poll() {
+            ::Poll::Success(item) => ::Poll::Success(g(item)),
+            ::Poll::Failure(e) => ::Poll::Failure(e),
+            ::Poll::NotReady(f) => ::Poll::NotReady(Map(f, g)),
+        }
+    }
+}
+
+/// Future returned by `Future::map_err()`.
+pub struct MapErr<F, G>(F, G);
+
+impl<F, G> MapErr<F, G> {
+    pub(crate) fn new(f: F, g: G) -> Self {
+        MapErr(f, g)
+    }
+}
+
+impl<F, G, U> Future for MapErr<F, G> where F: Future, G: FnOnce(F::Error) -> U {
+    type Item = F::Item;
+    type Error = U;
+
+    fn poll(self) -> ::Poll<Self> {
+        let MapErr(f, g) = self;
+
+        match f.poll() {
+            ::Poll::Success(item) => ::Poll::Success(item),
+            ::Poll::Failure(e) => ::Poll::Failure(g(e)),
+            ::Poll::NotReady(f) => ::Poll::NotReady(MapErr(f, g)),
+        }
+    }
+}
+
+/// Internal state shared by `Then`, `AndThen` and `OrElse`.
+///
+/// `First` holds the original future together with the closure that will produce the second
+/// future; `Second` holds the second future once it has been created.
+enum Chain<F, G, B> {
+    First(F, G),
+    Second(B),
+}
+
+/// Future returned by `Future::then()`.
+pub struct Then<F, G, B>(Chain<F, G, B>);
+
+impl<F, G, B> Then<F, G, B> {
+    pub(crate) fn new(f: F, g: G) -> Self {
+        Then(Chain::First(f, g))
+    }
+}
+
+impl<F, G, B> Future for Then<F, G, B> where F: Future, G: FnOnce(Result<F::Item, F::Error>) -> B, B: Future {
+    type Item = B::Item;
+    type Error = B::Error;
+
+    fn poll(mut self) -> ::Poll<Self> {
+        loop {
+            match self.0 {
+                Chain::First(f, g) => {
+                    match f.poll() {
+                        ::Poll::Success(item) => self.0 = Chain::Second(g(Ok(item))),
+                        ::Poll::Failure(e) => self.0 = Chain::Second(g(Err(e))),
+                        ::Poll::NotReady(f) => {
+                            self.0 = Chain::First(f, g);
+
+                            return ::Poll::NotReady(self);
+                        },
+                    }
+                },
+                Chain::Second(b) => {
+                    return match b.poll() {
+                        ::Poll::Success(item) => ::Poll::Success(item),
+                        ::Poll::Failure(e) => ::Poll::Failure(e),
+                        ::Poll::NotReady(b) => {
+                            self.0 = Chain::Second(b);
+
+                            ::Poll::NotReady(self)
+                        },
+                    };
+                },
+            }
+        }
+    }
+}
+
+/// Future returned by `Future::and_then()`.
+pub struct AndThen<F, G, B>(Chain<F, G, B>);
+
+impl<F, G, B> AndThen<F, G, B> {
+    pub(crate) fn new(f: F, g: G) -> Self {
+        AndThen(Chain::First(f, g))
+    }
+}
+
+impl<F, G, B> Future for AndThen<F, G, B> where F: Future, G: FnOnce(F::Item) -> B, B: Future<Error=F::Error> {
+    type Item = B::Item;
+    type Error = F::Error;
+
+    fn poll(mut self) -> ::Poll<Self> {
+        loop {
+            match self.0 {
+                Chain::First(f, g) => {
+                    match f.poll() {
+                        ::Poll::Success(item) => self.0 = Chain::Second(g(item)),
+                        ::Poll::Failure(e) => return ::Poll::Failure(e),
+                        ::Poll::NotReady(f) => {
+                            self.0 = Chain::First(f, g);
+
+                            return ::Poll::NotReady(self);
+                        },
+                    }
+                },
+                Chain::Second(b) => {
+                    return match b.poll() {
+                        ::Poll::Success(item) => ::Poll::Success(item),
+                        ::Poll::Failure(e) => ::Poll::Failure(e),
+                        ::Poll::NotReady(b) => {
+                            self.0 = Chain::Second(b);
+
+                            ::Poll::NotReady(self)
+                        },
+                    };
+                },
+            }
+        }
+    }
+}
+
+/// Future returned by `Future::or_else()`.
+pub struct OrElse<F, G, B>(Chain<F, G, B>);
+
+impl<F, G, B> OrElse<F, G, B> {
+    pub(crate) fn new(f: F, g: G) -> Self {
+        OrElse(Chain::First(f, g))
+    }
+}
+
+impl<F, G, B> Future for OrElse<F, G, B> where F: Future, G: FnOnce(F::Error) -> B, B: Future<Item=F::Item> {
+    type Item = F::Item;
+    type Error = B::Error;
+
+    fn poll(mut self) -> ::Poll<Self> {
+        loop {
+            match self.0 {
+                Chain::First(f, g) => {
+                    match f.poll() {
+                        ::Poll::Success(item) => return ::Poll::Success(item),
+                        ::Poll::Failure(e) => self.0 = Chain::Second(g(e)),
+                        ::Poll::NotReady(f) => {
+                            self.0 = Chain::First(f, g);
+
+                            return ::Poll::NotReady(self);
+                        },
+                    }
+                },
+                Chain::Second(b) => {
+                    return match b.poll() {
+                        ::Poll::Success(item) => ::Poll::Success(item),
+                        ::Poll::Failure(e) => ::Poll::Failure(e),
+                        ::Poll::NotReady(b) => {
+                            self.0 = Chain::Second(b);
+
+                            ::Poll::NotReady(self)
+                        },
+                    };
+                },
+            }
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use ::{Future, Poll};
+
+/// Future returned by `ok()`, immediately resolving with the given item on first poll.
+pub struct FutureOk<I, E>(I, ::std::marker::PhantomData<E>);
+
+/// Creates a future that resolves successfully with `item` as soon as it's polled.
+pub fn ok<I, E>(item: I) -> FutureOk<I, E> {
+    FutureOk(item, ::std::marker::PhantomData)
+}
+
+impl<I, E> Future for FutureOk<I, E> {
+    type Item = I;
+    type Error = E;
+
+    fn poll(self) -> Poll<Self> {
+        Poll::Success(self.0)
+    }
+}
+
+/// Future returned by `err()`, immediately failing with the given error on first poll.
+pub struct FutureErr<I, E>(E, ::std::marker::PhantomData<I>);
+
+/// Creates a future that fails with `error` as soon as it's polled.
+pub fn err<I, E>(error: E) -> FutureErr<I, E> {
+    FutureErr(error, ::std::marker::PhantomData)
+}
+
+impl<I, E> Future for FutureErr<I, E> {
+    type Item = I;
+    type Error = E;
+
+    fn poll(self) -> Poll<Self> {
+        Poll::Failure(self.0)
+    }
+}
+
+/// Future returned by `pending()`, which never resolves.
+pub struct Pending<I, E>(::std::marker::PhantomData<(I, E)>);
+
+/// Creates a future that is never ready, no matter how many times it's polled.
+pub fn pending<I, E>() -> Pending<I, E> {
+    Pending(::std::marker::PhantomData)
+}
+
+impl<I, E> Future for Pending<I, E> {
+    type Item = I;
+    type Error = E;
+
+    fn poll(self) -> Poll<Self> {
+        Poll::NotReady(self)
+    }
+}
+
+/// Future returned by `lazy()`.
+///
+/// Defers calling the closure that produces the underlying future until the first `poll()`.
+pub enum Lazy<G, F> {
+    Deferred(G),
+    Running(F),
+}
+
+/// Creates a future that calls `g` to produce the real future the first time it's polled, then
+/// drives that future to completion.
+pub fn lazy<G, F>(g: G) -> Lazy<G, F> where G: FnOnce() -> F, F: Future {
+    Lazy::Deferred(g)
+}
+
+impl<G, F> Future for Lazy<G, F> where G: FnOnce() -> F, F: Future {
+    type Item = F::Item;
+    type Error = F::Error;
+
+    fn poll(self) -> Poll<Self> {
+        let f = match self {
+            Lazy::Deferred(g) => g(),
+            Lazy::Running(f) => f,
+        };
+
+        match f.poll() {
+            Poll::Success(item) => Poll::Success(item),
+            Poll::Failure(e) => Poll::Failure(e),
+            Poll::NotReady(f) => Poll::NotReady(Lazy::Running(f)),
+        }
+    }
+}
+
+/// Future returned by `poll_fn()`.
+pub struct PollFn<G>(G);
+
+/// Creates a future driven entirely by `g`, which is called on every `poll()` and must return
+/// `Poll0::NotReady` to remain pending - the returned future takes care of carrying the
+/// closure itself forward.
+pub fn poll_fn<G, I, E>(g: G) -> PollFn<G> where G: FnMut() -> Poll0<I, E> {
+    PollFn(g)
+}
+
+/// The closure-friendly counterpart of `Poll<F>`: it doesn't know about the future carrying it,
+/// so it reports `NotReady` with no payload instead of `NotReady(F)`.
+pub enum Poll0<I, E> {
+    Success(I),
+    Failure(E),
+    NotReady,
+}
+
+impl<G, I, E> Future for PollFn<G> where G: FnMut() -> Poll0<I, E> {
+    type Item = I;
+    type Error = E;
+
+    fn poll(mut self) -> Poll<Self> {
+        match (self.0)() {
+            Poll0::Success(item) => Poll::Success(item),
+            Poll0::Failure(e) => Poll::Failure(e),
+            Poll0::NotReady => Poll::NotReady(self),
+        }
+    }
+}
@@ -111,38 +111,45 @@ impl<F: Future> UnsafeFuture for TSFuture<F> {
 ///
 /// FutureObject always contains unresolved future. Once the future is resolved, it must cease to
 /// exist.
-pub struct FutureObject<I, E> (Box<UnsafeFuture<Item=I, Error=E>>);
+///
+/// This is just `LocalFutureObject` specialized to `'static`, for futures that don't borrow
+/// anything.
+pub type FutureObject<I, E> = LocalFutureObject<'static, I, E>;
 
-impl<I, E> FutureObject<I, E> {
-    /// Creates `FutureObject` using type safe implementation of future.
-    pub fn from_type_safe_future<F: 'static + Future<Item=I, Error=E>>(future: F) -> Self {
-        FutureObject(Box::new(TSFuture::new(future)))
+/// Boxes a `Future` that borrows data with lifetime `'a`.
+///
+/// Like `FutureObject`, but the underlying future only needs to outlive `'a` instead of
+/// `'static`, so it can capture borrowed state. `FutureObject<I, E>` is just
+/// `LocalFutureObject<'static, I, E>`.
+///
+/// LocalFutureObject always contains unresolved future. Once the future is resolved, it must
+/// cease to exist.
+pub struct LocalFutureObject<'a, I, E>(Box<UnsafeFuture<Item=I, Error=E> + 'a>);
+
+impl<'a, I, E> LocalFutureObject<'a, I, E> {
+    /// Creates `LocalFutureObject` using type safe implementation of future.
+    pub fn from_type_safe_future<F: 'a + Future<Item=I, Error=E>>(future: F) -> Self {
+        LocalFutureObject(Box::new(TSFuture::new(future)))
     }
 
-    /// Creates `FutureObject` using type unsafe implementation of future.
-    pub fn from_type_unsafe_future<F: 'static + ::futures::Future<Item=I, Error=E>>(future: F) -> Self {
-        FutureObject(Box::new(TUFuture::new(future)))
+    /// Creates `LocalFutureObject` using type unsafe implementation of future.
+    pub fn from_type_unsafe_future<F: 'a + ::futures::Future<Item=I, Error=E>>(future: F) -> Self {
+        LocalFutureObject(Box::new(TUFuture::new(future)))
     }
 }
 
-impl<I, E> Future for FutureObject<I, E> {
+impl<'a, I, E> Future for LocalFutureObject<'a, I, E> {
     type Item = I;
     type Error = E;
 
     fn poll(mut self) -> ::Poll<Self> {
-        // This code operates on `UnsafeFuture`, so it must make sure that `poll` isn't called
-        // after future is resolved. Since it has safe interface, it has to make such call
-        // statically impossible.
+        // `LocalFutureObject` always holds an unresolved future (it is consumed once the future
+        // resolves), so calling `UnsafeFuture::poll()` here is correct.
         unsafe {
-            // The FutureObject must contain only unresolved future. Therefore calling poll() here is
-            // correct.
             let res = self.0.poll();
             match res {
-                // self is not kept here but dropped because the future is resolved now
                 Ok(::futures::Async::Ready(item)) => ::Poll::Success(item),
-                // Since the future is not resolved, self is returned to allow polling again
                 Ok(::futures::Async::NotReady) => ::Poll::NotReady(self),
-                // self is not kept here but dropped because the future is resolved now
                 Err(error) => ::Poll::Failure(error),
             }
         }
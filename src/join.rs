@@ -0,0 +1,132 @@
+use ::Future;
+
+/// A single slot in a `Join2`/`JoinAll`, tracking whether the future it holds has resolved yet.
+enum Slot<F: Future> {
+    Pending(F),
+    Done(F::Item),
+}
+
+/// Future returned by `Future::join()`.
+pub struct Join2<A: Future, B: Future<Error=A::Error>>(Slot<A>, Slot<B>);
+
+impl<A: Future, B: Future<Error=A::Error>> Join2<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        Join2(Slot::Pending(a), Slot::Pending(b))
+    }
+}
+
+impl<A: Future, B: Future<Error=A::Error>> Future for Join2<A, B> {
+    type Item = (A::Item, B::Item);
+    type Error = A::Error;
+
+    fn poll(mut self) -> ::Poll<Self> {
+        if let Slot::Pending(a) = self.0 {
+            match a.poll() {
+                ::Poll::Success(item) => self.0 = Slot::Done(item),
+                ::Poll::Failure(e) => return ::Poll::Failure(e),
+                ::Poll::NotReady(a) => self.0 = Slot::Pending(a),
+            }
+        }
+
+        if let Slot::Pending(b) = self.1 {
+            match b.poll() {
+                ::Poll::Success(item) => self.1 = Slot::Done(item),
+                ::Poll::Failure(e) => return ::Poll::Failure(e),
+                ::Poll::NotReady(b) => self.1 = Slot::Pending(b),
+            }
+        }
+
+        match (self.0, self.1) {
+            (Slot::Done(a), Slot::Done(b)) => ::Poll::Success((a, b)),
+            (a, b) => {
+                self.0 = a;
+                self.1 = b;
+
+                ::Poll::NotReady(self)
+            },
+        }
+    }
+}
+
+/// Future returned by `join_all()`.
+///
+/// Resolves to a `Vec` of the items of every input future, in the same order the futures were
+/// given in. Requires all of the input futures to share the same `Item`/`Error` types.
+pub struct JoinAll<F: Future>(Vec<Slot<F>>);
+
+/// Creates a `JoinAll` that drives every future in `futures` to completion concurrently.
+pub fn join_all<I>(futures: I) -> JoinAll<I::Item> where I: IntoIterator, I::Item: Future {
+    JoinAll(futures.into_iter().map(Slot::Pending).collect())
+}
+
+impl<F: Future> Future for JoinAll<F> {
+    type Item = Vec<F::Item>;
+    type Error = F::Error;
+
+    fn poll(self) -> ::Poll<Self> {
+        let mut slots = Vec::with_capacity(self.0.len());
+        let mut all_done = true;
+
+        for slot in self.0 {
+            let slot = match slot {
+                Slot::Pending(f) => match f.poll() {
+                    ::Poll::Success(item) => Slot::Done(item),
+                    ::Poll::Failure(e) => return ::Poll::Failure(e),
+                    ::Poll::NotReady(f) => {
+                        all_done = false;
+
+                        Slot::Pending(f)
+                    },
+                },
+                done @ Slot::Done(_) => done,
+            };
+
+            slots.push(slot);
+        }
+
+        if all_done {
+            ::Poll::Success(slots.into_iter().map(|slot| match slot {
+                Slot::Done(item) => item,
+                Slot::Pending(_) => unreachable!(),
+            }).collect())
+        } else {
+            ::Poll::NotReady(JoinAll(slots))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::constructors::{err, ok};
+
+    #[test]
+    fn join2_resolves_with_both_items_once_ready() {
+        let joined = Join2::new(ok::<i32, &'static str>(1), ok::<i32, &'static str>(2));
+
+        match joined.poll() {
+            ::Poll::Success((a, b)) => assert_eq!((a, b), (1, 2)),
+            _ => panic!("expected Join2 to resolve"),
+        }
+    }
+
+    #[test]
+    fn join2_short_circuits_on_first_failure() {
+        let joined = Join2::new(err::<i32, &'static str>("boom"), ok::<i32, &'static str>(2));
+
+        match joined.poll() {
+            ::Poll::Failure(e) => assert_eq!(e, "boom"),
+            _ => panic!("expected Join2 to fail"),
+        }
+    }
+
+    #[test]
+    fn join_all_resolves_with_items_in_order() {
+        let joined = join_all(vec![ok::<i32, &'static str>(1), ok(2), ok(3)]);
+
+        match joined.poll() {
+            ::Poll::Success(items) => assert_eq!(items, vec![1, 2, 3]),
+            _ => panic!("expected JoinAll to resolve"),
+        }
+    }
+}
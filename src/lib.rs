@@ -1,7 +1,19 @@
 extern crate futures;
 extern crate unreachable;
 
+pub mod catch_unwind;
+pub mod combinator;
+pub mod constructors;
 pub mod future_object;
+pub mod join;
+pub mod on_poll;
+pub mod select;
+
+use catch_unwind::CatchUnwind;
+use combinator::{AndThen, Map, MapErr, OrElse, Then};
+use join::Join2;
+use on_poll::{OnPoll, OnPollPending, OnPollReady, PollRef};
+use select::Select;
 
 pub enum Poll<F: Future> {
     Success(F::Item),
@@ -18,6 +30,73 @@ pub trait Future: Sized {
     fn glue(self) -> Glue<Self> {
         Glue::Valid(self)
     }
+
+    /// Maps the successful item of this future using the given closure.
+    fn map<G, U>(self, g: G) -> Map<Self, G> where G: FnOnce(Self::Item) -> U {
+        Map::new(self, g)
+    }
+
+    /// Maps the error of this future using the given closure.
+    fn map_err<G, U>(self, g: G) -> MapErr<Self, G> where G: FnOnce(Self::Error) -> U {
+        MapErr::new(self, g)
+    }
+
+    /// Chains a computation that runs regardless of whether `self` succeeds or fails.
+    fn then<G, B>(self, g: G) -> Then<Self, G, B> where G: FnOnce(Result<Self::Item, Self::Error>) -> B, B: Future {
+        Then::new(self, g)
+    }
+
+    /// Chains a computation that runs only if `self` succeeds, passing the error through otherwise.
+    fn and_then<G, B>(self, g: G) -> AndThen<Self, G, B> where G: FnOnce(Self::Item) -> B, B: Future<Error=Self::Error> {
+        AndThen::new(self, g)
+    }
+
+    /// Chains a computation that runs only if `self` fails, passing the item through otherwise.
+    fn or_else<G, B>(self, g: G) -> OrElse<Self, G, B> where G: FnOnce(Self::Error) -> B, B: Future<Item=Self::Item> {
+        OrElse::new(self, g)
+    }
+
+    /// Waits for `self` and `other` to both resolve, returning both items.
+    ///
+    /// If either future fails, the other is dropped and the error is returned immediately.
+    fn join<B>(self, other: B) -> Join2<Self, B> where B: Future<Error=Self::Error> {
+        Join2::new(self, other)
+    }
+
+    /// Races `self` against `other`, resolving with whichever settles first.
+    ///
+    /// The loser is not dropped; it is returned alongside the winner's result so the caller can
+    /// keep polling it.
+    fn select<B>(self, other: B) -> Select<Self, B> where B: Future<Item=Self::Item, Error=Self::Error> {
+        Select::new(self, other)
+    }
+
+    /// Catches panics from this future's `poll()`, turning them into a value instead of letting
+    /// them unwind past this point.
+    ///
+    /// This is the only safe way to recover from a panicking `poll()`: polling the original
+    /// future again afterwards is UB, but the resulting `CatchUnwind` can simply be dropped.
+    fn catch_unwind(self) -> CatchUnwind<Self> where Self: ::std::panic::UnwindSafe {
+        CatchUnwind::new(self)
+    }
+
+    /// Calls `g` with a reference to the result of every `poll()`, without affecting it.
+    ///
+    /// Since this crate has no `Context`/waker, this is purely observational - a lightweight way
+    /// to trace how a future progresses through `Glue` without modifying it.
+    fn on_poll<G>(self, g: G) -> OnPoll<Self, G> where G: FnMut(PollRef<Self>) {
+        OnPoll::new(self, g)
+    }
+
+    /// Like `on_poll()`, but `g` only runs when this future resolves (successfully or not).
+    fn on_poll_ready<G>(self, g: G) -> OnPollReady<Self, G> where G: FnMut(Result<&Self::Item, &Self::Error>) {
+        OnPollReady::new(self, g)
+    }
+
+    /// Like `on_poll()`, but `g` only runs when this future is not yet ready.
+    fn on_poll_pending<G>(self, g: G) -> OnPollPending<Self, G> where G: FnMut(&Self) {
+        OnPollPending::new(self, g)
+    }
 }
 
 /// This implements futures::Future with panicking if `poll()` is called on resolved future.
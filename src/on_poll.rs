@@ -0,0 +1,109 @@
+use ::Future;
+
+/// Reference to the result of polling the inner future, passed to an `OnPoll` closure.
+///
+/// This crate has no `Context`/waker, so the hooks in this module are purely observational: they
+/// let a closure see how a future progresses (e.g. for logging or metrics) without being able to
+/// influence it.
+pub enum PollRef<'a, F: Future + 'a> {
+    Success(&'a F::Item),
+    Failure(&'a F::Error),
+    NotReady(&'a F),
+}
+
+/// Future returned by `Future::on_poll()`.
+pub struct OnPoll<F, G>(F, G);
+
+impl<F, G> OnPoll<F, G> {
+    pub(crate) fn new(f: F, g: G) -> Self {
+        OnPoll(f, g)
+    }
+}
+
+impl<F, G> Future for OnPoll<F, G> where F: Future, G: FnMut(PollRef<F>) {
+    type Item = F::Item;
+    type Error = F::Error;
+
+    fn poll(self) -> ::Poll<Self> {
+        let OnPoll(f, mut g) = self;
+        let res = f.poll();
+
+        match res {
+            ::Poll::Success(item) => {
+                g(PollRef::Success(&item));
+
+                ::Poll::Success(item)
+            },
+            ::Poll::Failure(e) => {
+                g(PollRef::Failure(&e));
+
+                ::Poll::Failure(e)
+            },
+            ::Poll::NotReady(f) => {
+                g(PollRef::NotReady(&f));
+
+                ::Poll::NotReady(OnPoll(f, g))
+            },
+        }
+    }
+}
+
+/// Future returned by `Future::on_poll_ready()`.
+pub struct OnPollReady<F, G>(F, G);
+
+impl<F, G> OnPollReady<F, G> {
+    pub(crate) fn new(f: F, g: G) -> Self {
+        OnPollReady(f, g)
+    }
+}
+
+impl<F, G> Future for OnPollReady<F, G> where F: Future, G: FnMut(Result<&F::Item, &F::Error>) {
+    type Item = F::Item;
+    type Error = F::Error;
+
+    fn poll(self) -> ::Poll<Self> {
+        let OnPollReady(f, mut g) = self;
+
+        match f.poll() {
+            ::Poll::Success(item) => {
+                g(Ok(&item));
+
+                ::Poll::Success(item)
+            },
+            ::Poll::Failure(e) => {
+                g(Err(&e));
+
+                ::Poll::Failure(e)
+            },
+            ::Poll::NotReady(f) => ::Poll::NotReady(OnPollReady(f, g)),
+        }
+    }
+}
+
+/// Future returned by `Future::on_poll_pending()`.
+pub struct OnPollPending<F, G>(F, G);
+
+impl<F, G> OnPollPending<F, G> {
+    pub(crate) fn new(f: F, g: G) -> Self {
+        OnPollPending(f, g)
+    }
+}
+
+impl<F, G> Future for OnPollPending<F, G> where F: Future, G: FnMut(&F) {
+    type Item = F::Item;
+    type Error = F::Error;
+
+    fn poll(self) -> ::Poll<Self> {
+        let OnPollPending(f, mut g) = self;
+
+        match f.poll() {
+            ::Poll::Success(item) => ::Poll::Success(item),
+            ::Poll::Failure(e) => ::Poll::Failure(e),
+            ::Poll::NotReady(f) => {
+                g(&f);
+
+                ::Poll::NotReady(OnPollPending(f, g))
+            },
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use ::Future;
+
+/// The result of `Select<A, B>` resolving: which future won, its result, and the other future
+/// so the caller can keep driving it if they wish.
+pub enum Selected<A, B> where A: Future, B: Future<Item=A::Item, Error=A::Error> {
+    A(Result<A::Item, A::Error>, B),
+    B(Result<B::Item, B::Error>, A),
+}
+
+/// Future returned by `Future::select()`.
+///
+/// Resolves as soon as either `A` or `B` resolves, yielding the winner's result together with
+/// the other, still-unresolved future so the caller can keep driving it if they wish.
+pub struct Select<A, B>(A, B);
+
+impl<A, B> Select<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        Select(a, b)
+    }
+}
+
+impl<A, B> Future for Select<A, B> where A: Future, B: Future<Item=A::Item, Error=A::Error> {
+    type Item = Selected<A, B>;
+    type Error = ::std::convert::Infallible;
+
+    fn poll(self) -> ::Poll<Self> {
+        let Select(a, b) = self;
+
+        match a.poll() {
+            ::Poll::Success(item) => ::Poll::Success(Selected::A(Ok(item), b)),
+            ::Poll::Failure(e) => ::Poll::Success(Selected::A(Err(e), b)),
+            ::Poll::NotReady(a) => {
+                match b.poll() {
+                    ::Poll::Success(item) => ::Poll::Success(Selected::B(Ok(item), a)),
+                    ::Poll::Failure(e) => ::Poll::Success(Selected::B(Err(e), a)),
+                    ::Poll::NotReady(b) => ::Poll::NotReady(Select(a, b)),
+                }
+            },
+        }
+    }
+}
+
+/// Future returned by `select_all()`.
+///
+/// Resolves as soon as any of the futures in the `Vec` resolves, yielding its result, its index
+/// in the original `Vec` and the remaining, still-unresolved futures.
+pub struct SelectAll<F>(Vec<F>, usize);
+
+/// Creates a `SelectAll` racing every future in `futures`.
+///
+/// The futures are polled in `Vec` order, wrapping around. The first poll starts from index `0`;
+/// every poll that leaves all of them not ready moves the starting index one further along.
+pub fn select_all<F: Future>(futures: Vec<F>) -> SelectAll<F> {
+    SelectAll(futures, 0)
+}
+
+impl<F: Future> Future for SelectAll<F> {
+    type Item = (Result<F::Item, F::Error>, usize, Vec<F>);
+    type Error = ::std::convert::Infallible;
+
+    fn poll(self) -> ::Poll<Self> {
+        let SelectAll(futures, start) = self;
+        let len = futures.len();
+
+        if len == 0 {
+            return ::Poll::NotReady(SelectAll(futures, start));
+        }
+
+        let mut slots: Vec<Option<F>> = futures.into_iter().map(Some).collect();
+
+        for offset in 0..len {
+            let index = (start + offset) % len;
+            let f = slots[index].take().expect("SelectAll polled after completion");
+
+            match f.poll() {
+                ::Poll::Success(item) => return ::Poll::Success((Ok(item), index, Self::remaining(slots, index))),
+                ::Poll::Failure(e) => return ::Poll::Success((Err(e), index, Self::remaining(slots, index))),
+                ::Poll::NotReady(f) => slots[index] = Some(f),
+            }
+        }
+
+        let futures = slots.into_iter().map(|f| f.expect("SelectAll lost a future")).collect();
+
+        ::Poll::NotReady(SelectAll(futures, (start + 1) % len))
+    }
+}
+
+impl<F: Future> SelectAll<F> {
+    /// Collects every slot except `resolved`, which has already been taken out as the winner.
+    fn remaining(slots: Vec<Option<F>>, resolved: usize) -> Vec<F> {
+        slots.into_iter().enumerate()
+            .filter(|&(i, _)| i != resolved)
+            .map(|(_, f)| f.expect("SelectAll lost a future"))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::constructors::{ok, FutureOk};
+
+    #[test]
+    fn select_resolves_via_first_ready_future() {
+        let select = Select::new(ok::<i32, &'static str>(1), ok::<i32, &'static str>(2));
+
+        match select.poll() {
+            ::Poll::Success(Selected::A(Ok(item), _)) => assert_eq!(item, 1),
+            _ => panic!("expected Select to resolve via A"),
+        }
+    }
+
+    #[test]
+    fn select_all_on_empty_vec_stays_not_ready() {
+        let futures: Vec<FutureOk<i32, &'static str>> = Vec::new();
+
+        match select_all(futures).poll() {
+            ::Poll::NotReady(_) => {},
+            _ => panic!("expected empty SelectAll to stay NotReady rather than panic"),
+        }
+    }
+
+    #[test]
+    fn select_all_resolves_with_winner_index_and_remaining() {
+        let futures = vec![ok::<i32, &'static str>(1), ok(2), ok(3)];
+
+        match select_all(futures).poll() {
+            ::Poll::Success((Ok(item), index, remaining)) => {
+                assert_eq!(item, 1);
+                assert_eq!(index, 0);
+                assert_eq!(remaining.len(), 2);
+            },
+            _ => panic!("expected SelectAll to resolve via the first future"),
+        }
+    }
+}